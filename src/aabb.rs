@@ -0,0 +1,48 @@
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+// Axis-aligned bounding box, used by `BvhNode` to skip whole subtrees that a
+// ray can't possibly hit.
+#[derive(Clone, Copy)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3) -> Aabb {
+        Aabb { min, max }
+    }
+
+    pub fn surrounding_box(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb {
+            min: Vec3::new(a.min.x.min(b.min.x), a.min.y.min(b.min.y), a.min.z.min(b.min.z)),
+            max: Vec3::new(a.max.x.max(b.max.x), a.max.y.max(b.max.y), a.max.z.max(b.max.z)),
+        }
+    }
+
+    // Slab test: intersect the ray's parameter interval with the interval it
+    // spends between each pair of parallel planes, on every axis.
+    pub fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> bool {
+        let (t_min, t_max) = Self::hit_slab(self.min.x, self.max.x, ray.origin.x, ray.direction.x, t_min, t_max);
+        if t_max <= t_min {
+            return false;
+        }
+        let (t_min, t_max) = Self::hit_slab(self.min.y, self.max.y, ray.origin.y, ray.direction.y, t_min, t_max);
+        if t_max <= t_min {
+            return false;
+        }
+        let (t_min, t_max) = Self::hit_slab(self.min.z, self.max.z, ray.origin.z, ray.direction.z, t_min, t_max);
+        t_max > t_min
+    }
+
+    fn hit_slab(min: f32, max: f32, origin: f32, direction: f32, t_min: f32, t_max: f32) -> (f32, f32) {
+        let inv_d = 1.0 / direction;
+        let mut t0 = (min - origin) * inv_d;
+        let mut t1 = (max - origin) * inv_d;
+        if inv_d < 0.0 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        (t0.max(t_min), t1.min(t_max))
+    }
+}
@@ -0,0 +1,106 @@
+use crate::aabb::Aabb;
+use crate::hittable::{Hit, Hittable};
+use crate::ray::Ray;
+
+// A binary tree over `objects`' bounding boxes: `hit` rejects a whole subtree
+// with a single box test instead of checking every object it contains,
+// turning a linear `Scene::hit` scan into roughly O(log n).
+pub struct BvhNode {
+    left: Box<dyn Hittable>,
+    right: Option<Box<dyn Hittable>>,
+    bbox: Aabb,
+}
+
+#[test]
+fn matches_linear_scan() {
+    use std::sync::Arc;
+
+    use crate::material::{Lambertian, Material};
+    use crate::sphere::Sphere;
+    use crate::vec3::Vec3;
+
+    let material = Arc::new(Lambertian { albedo: Vec3::new(0.5, 0.5, 0.5) }) as Arc<dyn Material>;
+    let spheres: Vec<Box<dyn Hittable>> = (0..5).map(|i| Box::new(Sphere {
+        radius: 1.0,
+        position: Vec3::new(i as f32 * 3.0, 0.0, 0.0),
+        material: material.clone(),
+    }) as Box<dyn Hittable>).collect();
+
+    let ray = Ray { origin: Vec3::new(-10.0, 0.0, 0.0), direction: Vec3::new(1.0, 0.0, 0.0), time: 0.0 };
+
+    let linear = spheres.iter()
+        .filter_map(|object| object.hit(&ray, 0.001, f32::INFINITY))
+        .min_by(|a, b| a.t.partial_cmp(&b.t).unwrap())
+        .map(|hit| hit.t);
+
+    let bvh = BvhNode::new(spheres);
+    assert_eq!(bvh.hit(&ray, 0.001, f32::INFINITY).map(|hit| hit.t), linear);
+}
+
+impl BvhNode {
+    pub fn new(mut objects: Vec<Box<dyn Hittable>>) -> BvhNode {
+        assert!(!objects.is_empty(), "BvhNode needs at least one object");
+
+        let axis = Self::longest_axis(&objects);
+        objects.sort_by(|a, b| Self::axis_min(a.as_ref(), axis)
+            .partial_cmp(&Self::axis_min(b.as_ref(), axis))
+            .unwrap());
+
+        if objects.len() == 1 {
+            let only = objects.pop().unwrap();
+            let bbox = only.bounding_box();
+            return BvhNode { left: only, right: None, bbox };
+        }
+
+        let rest = objects.split_off(objects.len() / 2);
+        let left: Box<dyn Hittable> = Box::new(BvhNode::new(objects));
+        let right: Box<dyn Hittable> = Box::new(BvhNode::new(rest));
+        let bbox = Aabb::surrounding_box(&left.bounding_box(), &right.bounding_box());
+        BvhNode { left, right: Some(right), bbox }
+    }
+
+    // Splitting along the box's longest extent tends to produce the most
+    // balanced, least-overlapping subtrees.
+    fn longest_axis(objects: &[Box<dyn Hittable>]) -> usize {
+        let bbox = objects.iter()
+            .map(|object| object.bounding_box())
+            .reduce(|acc, bbox| Aabb::surrounding_box(&acc, &bbox))
+            .unwrap();
+        let extent = bbox.max - bbox.min;
+
+        if extent.x > extent.y && extent.x > extent.z {
+            0
+        } else if extent.y > extent.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn axis_min(object: &dyn Hittable, axis: usize) -> f32 {
+        let bbox = object.bounding_box();
+        match axis {
+            0 => bbox.min.x,
+            1 => bbox.min.y,
+            _ => bbox.min.z,
+        }
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        if !self.bbox.hit(ray, t_min, t_max) {
+            return None;
+        }
+
+        let left_hit = self.left.hit(ray, t_min, t_max);
+        let closest = left_hit.as_ref().map_or(t_max, |hit| hit.t);
+        let right_hit = self.right.as_ref().and_then(|right| right.hit(ray, t_min, closest));
+
+        right_hit.or(left_hit)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.bbox
+    }
+}
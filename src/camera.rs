@@ -0,0 +1,62 @@
+use rand::Rng;
+
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct Camera {
+    position: Vec3,
+    direction: Vec3,
+    height: Vec3,
+    aperture: f32,
+    focus_dist: f32,
+    time0: f32,
+    time1: f32,
+}
+
+impl Camera {
+    pub fn new(position: Vec3, direction: Vec3, height: Vec3, aperture: f32, focus_dist: f32, time0: f32, time1: f32) -> Camera {
+        Camera {
+            position,
+            direction,
+            height,
+            aperture,
+            focus_dist,
+            time0,
+            time1,
+        }
+    }
+
+    // Vector of same length and perpendicular to both height and direction.
+    fn width(&self) -> Vec3 {
+        let w = self.direction.cross(self.height);
+        w / w.length() * self.height.length()
+    }
+
+    // `x`/`y` are sample positions in pixel space, not necessarily integral:
+    // callers jitter them within a pixel for anti-aliasing.
+    pub fn ray(&self, x: f32, y: f32, width: u16, height: u16) -> Ray {
+        let vertical_offset = (x / width as f32) - 0.5;
+        let horizontal_offset = (y / height as f32) - 0.5;
+
+        // Scale the viewport to sit on the focal plane rather than wherever
+        // `direction`'s own length happens to put it.
+        let focus_scale = self.focus_dist / self.direction.length();
+        let target = self.position
+            + (self.direction + self.height*vertical_offset + self.width()*horizontal_offset) * focus_scale;
+
+        // Jitter the ray origin across a lens of radius `aperture/2`, aimed
+        // through the same focal-plane target, so out-of-focus points blur.
+        let lens_offset = Vec3::random_in_unit_disc() * (self.aperture / 2.0);
+        let origin = self.position
+            + self.width().unit() * lens_offset.x
+            + self.height.unit() * lens_offset.y;
+
+        let time = self.time0 + rand::thread_rng().gen::<f32>() * (self.time1 - self.time0);
+
+        Ray {
+            origin,
+            direction: target - origin,
+            time,
+        }
+    }
+}
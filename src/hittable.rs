@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::bvh::BvhNode;
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct Hit {
+    pub t: f32,
+    pub point: Vec3,
+    pub normal: Vec3,
+    pub material: Arc<dyn Material>,
+}
+
+// `Send + Sync` so a `Scene` can be shared across the tile-rendering worker threads.
+pub trait Hittable: Send + Sync {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit>;
+    fn bounding_box(&self) -> Aabb;
+}
+
+// Built on a `BvhNode` rather than scanning `objects` linearly, so `hit`
+// costs roughly O(log n) instead of O(n).
+pub struct Scene {
+    bvh: BvhNode,
+}
+
+impl Scene {
+    pub fn new(objects: Vec<Box<dyn Hittable>>) -> Scene {
+        Scene { bvh: BvhNode::new(objects) }
+    }
+}
+
+impl Hittable for Scene {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        self.bvh.hit(ray, t_min, t_max)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.bvh.bounding_box()
+    }
+}
@@ -6,8 +6,6 @@
 // - GI with specularity
 //
 // Path tracing:
-// - reflections (one bounce)
-// - diffusion (random bounce)
 // - opacity / diffraction
 // - ray perturbation (soft edges / anti aliasing)
 // - light sources
@@ -15,27 +13,25 @@
 // - simple non-spherical geometries
 // - loading and rendering 3D models
 // - accumulating rays (rendering a noisy version first and improving from there)
-// - Depth of Field
 //
 // Yak shaving:
 // - choosing float precision at runtime
-// - multithreading
 // - nalgebra version
 // - ultraviolet version?
 // - microbenchmarks with criterion
-// - handling cli arguments with that crate I don't remember now
 // - change settings at runtime with keybinds
 // - wasm version for web
 // - Vulkan raytracing APIs (https://github.com/GPSnoopy/RayTracingInVulkan,
 //                           https://github.com/vaffeine/vulkano-raytracing)
 // - stereoscopic views (see https://www.iquilezles.org/www/index.htm)
-// - moving camera + other controls (viewport size, focal distance)
+// - moving camera + other controls (viewport size)
 // - saving midpoint as image
 // - saving rendering process as video/gif
 // - fancy compression for images and video
 // - GUI for settings with dear imgui
 
 use std::io::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use pixels::{Pixels, SurfaceTexture};
@@ -44,240 +40,176 @@ use winit::event::{Event, WindowEvent};
 use winit::event_loop::{ControlFlow, EventLoop};
 use winit::window::WindowBuilder;
 
+mod aabb;
+mod bvh;
+mod camera;
+mod hittable;
+mod material;
+mod moving_sphere;
+mod ray;
+mod render;
+mod scene_file;
+mod sphere;
 mod vec3;
-use vec3::Vec3;
 
-const WIDTH: u16 = 255;
-const HEIGHT: u16 = 255;
-const SCALE: u8 = 255;
+use std::sync::Arc;
 
-struct Ray {
-    origin: Vec3,
-    direction: Vec3,
-}
+use camera::Camera;
+use hittable::Scene;
+use material::Lambertian;
+use render::RenderSettings;
+use sphere::Sphere;
+use vec3::Vec3;
 
-struct Sphere {
-    radius: f32,
-    position: Vec3,
+const DEFAULT_WIDTH: u16 = 255;
+const DEFAULT_HEIGHT: u16 = 255;
+const MAX_DEPTH: u32 = 50;
+const DEFAULT_SAMPLES_PER_PIXEL: u32 = 100;
+
+struct Args {
+    binary: bool,
+    use_winit: bool,
+    samples_per_pixel: u32,
+    thread_count: usize,
+    scene_path: Option<PathBuf>,
+    output_path: Option<PathBuf>,
 }
 
-#[test]
-fn test_sphere_intersections() {
-    let s = Sphere { radius: 1.0, position: Vec3::new(0.0, 0.0, 0.0) };
-
-    // Ray intersects
-    assert_eq!(
-        s.intersect_t(&Ray {
-            origin: Vec3::new(-10.0, 0.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        Some(9.0));
-
-    // Ray points wrong way
-    assert_eq!(
-        s.intersect_t(&Ray {
-            origin: Vec3::new(-10.0, 0.0, 0.0),
-            direction: Vec3::new(-1.0, 0.0, 0.0),
-        }),
-        None);
-
-    // Ray starts inside
-    assert_eq!(
-        s.intersect_t(&Ray {
-            origin: Vec3::new(0.0, 0.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        Some(1.0));
-
-    // Ray hits edge
-    assert_eq!(
-        s.intersect_t(&Ray {
-            origin: Vec3::new(-10.0, 1.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        Some(10.0));
-
-    // Ray misses
-    assert_eq!(
-        s.intersect_t(&Ray {
-            origin: Vec3::new(-10.0, 2.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        None);
-
-    assert_eq!(
-        s.intersect(&Ray {
-            origin: Vec3::new(-10.0, 0.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        Some(Vec3::new(-1.0, 0.0, 0.0)));
-    assert_eq!(
-        s.intersect(&Ray {
-            origin: Vec3::new(-10.0, 1.0, 0.0),
-            direction: Vec3::new(1.0, 0.0, 0.0),
-        }),
-        Some(Vec3::new(0.0, 1.0, 0.0)));
-}
-
-impl Sphere {
-    fn intersect_t(&self, r: &Ray) -> Option<f32> {
-        // If a point P is on the sphere S with radius r, centered at origin,
-        // it must satisfy:
-        //   â€–ğâ€– = r
-        // that is:
-        //   P_xÂ² + P_yÂ² + P_zÂ² = rÂ²
-        //
-        // If the sphere is not at origin:
-        //   â€–ğ-ğ’â€– = r
-        //   (P_x-S_x)Â² + (P_y-S_y)Â² + (P_z-S_z)Â² = rÂ²
-        //   (ğ-ğ’) â‹… (ğ-ğ’) = rÂ²
-        //
-        // A Point P(t) is on the Ray R with origin O and direction D, if for some tâ‰¥0:
-        //  ğ + tğƒ = ğ(t), so  O_c + tD_c = P_c, for every coordinate câˆˆ{x, y, z}
-        //
-        // Therefore a ray on the sphere needs to satisfy
-        //   (ğ + tğƒ âˆ’ ğ’)â‹…(ğ + tğƒ âˆ’ ğ’) = rÂ²
-        //   (ğƒ â‹… ğƒ)tÂ² + 2(ğƒ â‹… (ğ âˆ’ ğ’))t + (ğ âˆ’ ğ’)â‹…(ğ âˆ’ ğ’) âˆ’ rÂ² = 0
-        //
-        // Quadratic polynomials (axÂ²+bx+c=0) are solved by:
-        //   x = (-bÂ±âˆš(bÂ²-4ac)) / 2a
-        // where the part inside the square root is called the discriminant.
-
-        // (ğ âˆ’ ğ’)
-        let os = r.origin - self.position;
-
-        // a = ğƒ â‹… ğƒ, which is always positive
-        let a = r.direction.dot(r.direction);
-
-        // b = 2(ğƒ â‹… (ğ âˆ’ ğ’))
-        let b = 2.0 * r.direction.dot(os);
-
-        //   c = (ğ âˆ’ ğ’)â‹…(ğ âˆ’ ğ’) âˆ’ rÂ²
-        let c = os.dot(os) - self.radius * self.radius;
-        let discriminant = b*b - 4.0*a*c;
-
-        // The discriminant determines whether there is a solution, or more precisely
-        // if the ray misses the sphere (negative discriminant, giving an imaginary result),
-        if discriminant < 0.0 {
-            return None;
-        }
-
-        // if it hits an edge (zero),
-        let t1 = (-b - discriminant.sqrt()) / (2.0*a);
-        if t1 >= 0.0 {
-            // (since a is always positive, t1â‰¤t2 always,
-            //  so if t1 is not negative, it's the only or closer solution)
-            return Some(t1);
-        }
+// A small hand-rolled parser: `-b`/`-w` are presence flags, `-s`/`-t`/`-o`
+// take the following argument, and the one remaining bare argument (if any)
+// is the scene file to render.
+fn parse_args() -> Args {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut args = Args {
+        binary: false,
+        use_winit: false,
+        samples_per_pixel: DEFAULT_SAMPLES_PER_PIXEL,
+        thread_count: render::default_thread_count(),
+        scene_path: None,
+        output_path: None,
+    };
 
-        // or it goes through (positive, giving two real results).
-        let t2 = (-b + discriminant.sqrt()) / (2.0*a);
-        if t2 > 0.0 {
-            return Some(t2);
+    let mut i = 0;
+    while i < raw.len() {
+        match raw[i].as_str() {
+            "-b" => args.binary = true,
+            "-w" => args.use_winit = true,
+            "-s" => {
+                i += 1;
+                if let Some(value) = raw.get(i).and_then(|v| v.parse().ok()) {
+                    args.samples_per_pixel = value;
+                }
+            }
+            "-t" => {
+                i += 1;
+                if let Some(value) = raw.get(i).and_then(|v| v.parse().ok()) {
+                    args.thread_count = value;
+                }
+            }
+            "-o" | "--output" => {
+                i += 1;
+                if let Some(value) = raw.get(i) {
+                    args.output_path = Some(PathBuf::from(value));
+                }
+            }
+            positional => args.scene_path = Some(PathBuf::from(positional)),
         }
-
-        // If both solutions for t are negative,
-        // the ray is pointing the wrong way (hit point is "behind" the origin of ray)
-        None
+        i += 1;
     }
 
-    fn intersect(&self, r: &Ray) -> Option<Vec3> {
-        match self.intersect_t(r) {
-            None => None,
-            Some(t) => Some(r.origin + r.direction*t),
-        }
-    }
+    args
 }
 
-struct Camera {
-    position: Vec3,
-    direction: Vec3,
-    height: Vec3,
+fn build_scene() -> (Camera, Scene) {
+    let direction = Vec3::new(10.0, 0.0, 0.0);
+    let camera = Camera::new(
+        Vec3::new(-10.0, 0.0, 0.0),
+        direction,
+        Vec3::new(0.0, 1.0, 0.0),
+        0.0,
+        direction.length(),
+        0.0,
+        1.0,
+        );
+    let scene = Scene::new(vec![
+        Box::new(Sphere {
+            radius: 1.0,
+            position: Vec3::new(10.0, 0.0, 0.0),
+            material: Arc::new(Lambertian { albedo: Vec3::new(0.5, 0.5, 0.5) }),
+        }),
+    ]);
+    (camera, scene)
 }
 
-impl Camera {
-    fn new(position: Vec3, direction: Vec3, height: Vec3) -> Camera {
-        Camera {
-            position,
-            direction,
-            height,
+// Loads the scene named on the command line, or falls back to the
+// hardcoded demo scene so running the binary with no arguments still works.
+fn load_scene(args: &Args) -> Result<(Camera, Scene, RenderSettings)> {
+    match &args.scene_path {
+        Some(path) => {
+            let defaults = RenderSettings {
+                width: DEFAULT_WIDTH,
+                height: DEFAULT_HEIGHT,
+                samples_per_pixel: args.samples_per_pixel,
+                max_depth: MAX_DEPTH,
+            };
+            let scene_file = scene_file::load(path, defaults)?;
+            Ok((scene_file.camera, scene_file.scene, scene_file.settings))
+        }
+        None => {
+            let (camera, scene) = build_scene();
+            let settings = RenderSettings {
+                width: DEFAULT_WIDTH,
+                height: DEFAULT_HEIGHT,
+                samples_per_pixel: args.samples_per_pixel,
+                max_depth: MAX_DEPTH,
+            };
+            Ok((camera, scene, settings))
         }
-    }
-
-    // Vector of same length and perpendicular to both height and direction.
-    fn width(&self) -> Vec3 {
-        let w = self.direction.cross(self.height);
-        w / w.length() * self.height.length()
-    }
-
-    fn ray(&self, x: u16, y: u16) -> Ray {
-        let vertical_offset = (x as f32 / WIDTH as f32) - 0.5;
-        let horizontal_offset = (y as f32 / HEIGHT as f32) - 0.5;
-        return Ray {
-            origin: self.position,
-            direction: self.direction + self.height * vertical_offset + self.width() * horizontal_offset,
-        };
     }
 }
 
-fn arg(target : &str) -> bool {
-    return std::env::args().any(|x| x == target);
-}
+// Renders the whole image on a pool of worker threads and streams it out in
+// row-major order, so the PPM writer itself stays a simple serial loop.
+fn write_ppm(camera: &Camera, scene: &Scene, settings: &RenderSettings, thread_count: usize, binary: bool, output_path: Option<&std::path::Path>) -> Result<()> {
+    let buffer = render::render_parallel(camera, scene, settings, thread_count);
 
-fn get_gradient_color(x: u16, y: u16) -> [u8; 4] {
-    return [(x*SCALE as u16/WIDTH) as u8, (y*SCALE as u16/HEIGHT) as u8, 0, 255];
-}
-
-fn get_raydistance_color(x: u16, y: u16) -> [u8; 4] {
-    let camera = Camera::new(
-        Vec3::new(-10.0, 0.0, 0.0),
-        Vec3::new(10.0, 0.0, 0.0),
-        Vec3::new(0.0, 1.0, 0.0),
-        );
-    let sphere = Sphere { radius: 1.0, position: Vec3::new(10.0, 0.0, 0.0) };
-    match sphere.intersect_t(&camera.ray(x, y)) {
-        None => get_gradient_color(x, y),
-        Some(t) => [((t-1.9)*1000.0) as u8, 0, 0, 255],
-    }
-}
+    let mut writer: Box<dyn Write> = match output_path {
+        Some(path) => Box::new(std::fs::File::create(path)?),
+        None => Box::new(std::io::stdout()),
+    };
 
-fn write_ppm(binary : bool) {
-    if binary {
-        println!("P6");
-    } else {
-        println!("P3");
-    }
-    println!("{} {}", WIDTH, HEIGHT);
-    println!("{}", SCALE);
+    writeln!(writer, "{}", if binary { "P6" } else { "P3" })?;
+    writeln!(writer, "{} {}", settings.width, settings.height)?;
+    writeln!(writer, "255")?;
 
-    for i in 0..HEIGHT {
-        for j in 0..WIDTH {
-            if binary {
-                std::io::stdout().write(&get_raydistance_color(i, j)[..3]).unwrap();
-            } else {
-                let [r, g, b, _] = get_raydistance_color(i, j);
-                println!("{} {} {}", r, g, b);
-            }
+    for color in buffer {
+        if binary {
+            writer.write_all(&color[..3])?;
+        } else {
+            let [r, g, b, _] = color;
+            writeln!(writer, "{} {} {}", r, g, b)?;
         }
     }
+    Ok(())
 }
 
 fn main() -> Result<()> {
-    let binary = arg("-b");
-    let usewinit = arg("-w");
+    let args = parse_args();
+    let (camera, scene, settings) = load_scene(&args)?;
 
-    if usewinit {
-        run_winit()?;
+    if args.use_winit {
+        run_winit(camera, scene, settings)?;
     } else {
-        write_ppm(binary);
+        write_ppm(&camera, &scene, &settings, args.thread_count, args.binary, args.output_path.as_deref())?;
     }
     Ok(())
 }
 
-fn run_winit() -> Result<()> {
+fn run_winit(camera: Camera, scene: Scene, settings: RenderSettings) -> Result<()> {
     let event_loop = EventLoop::new();
     let window = {
-        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        let size = LogicalSize::new(settings.width as f64, settings.height as f64);
         WindowBuilder::new()
             .with_title("Raytracing in one weekend")
             .with_inner_size(size)
@@ -289,9 +221,13 @@ fn run_winit() -> Result<()> {
     let mut pixels = {
         let window_size = window.inner_size();
         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+        Pixels::new(settings.width as u32, settings.height as u32, surface_texture)?
     };
 
+    let pixel_count = settings.width as usize * settings.height as usize;
+    let mut accumulators = vec![Vec3::new(0.0, 0.0, 0.0); pixel_count];
+    let mut sample_counts = vec![0u32; pixel_count];
+
     event_loop.run(move |event, _, control_flow| {
         match event {
             Event::WindowEvent {
@@ -304,10 +240,14 @@ fn run_winit() -> Result<()> {
             Event::MainEventsCleared => {
                 let frame = pixels.get_frame();
                 for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
-                    let x = (i % WIDTH as usize) as u16;
-                    let y = (i / WIDTH as usize) as u16;
-
-                    pixel.copy_from_slice(&get_raydistance_color(x, y));
+                    let x = (i % settings.width as usize) as u16;
+                    let y = (i / settings.width as usize) as u16;
+
+                    if sample_counts[i] < settings.samples_per_pixel {
+                        accumulators[i] = accumulators[i] + render::sample_color(&camera, &scene, x, y, &settings);
+                        sample_counts[i] += 1;
+                    }
+                    pixel.copy_from_slice(&render::to_pixel(accumulators[i], sample_counts[i].max(1)));
                 }
                 if pixels.render().is_err() {
                     println!("EEEK!");
@@ -0,0 +1,77 @@
+use crate::hittable::Hit;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+// `Send + Sync` so a material can be shared across the tile-rendering worker threads.
+pub trait Material: Send + Sync {
+    fn scatter(&self, ray: &Ray, hit: &Hit) -> Option<(Ray, Vec3)>;
+}
+
+pub struct Lambertian {
+    pub albedo: Vec3,
+}
+
+impl Material for Lambertian {
+    fn scatter(&self, ray: &Ray, hit: &Hit) -> Option<(Ray, Vec3)> {
+        let mut direction = hit.normal + Vec3::random_unit_vector();
+        if direction.near_zero() {
+            direction = hit.normal;
+        }
+
+        Some((Ray { origin: hit.point, direction, time: ray.time }, self.albedo))
+    }
+}
+
+pub struct Metal {
+    pub albedo: Vec3,
+    pub fuzz: f32,
+}
+
+impl Material for Metal {
+    fn scatter(&self, ray: &Ray, hit: &Hit) -> Option<(Ray, Vec3)> {
+        let reflected = ray.direction.unit().reflect(hit.normal) + Vec3::random_in_unit_sphere() * self.fuzz;
+        if reflected.dot(hit.normal) > 0.0 {
+            Some((Ray { origin: hit.point, direction: reflected, time: ray.time }, self.albedo))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Dielectric {
+    pub refraction_index: f32,
+}
+
+impl Dielectric {
+    // Schlick's approximation for the reflectance of a dielectric,
+    // varying with viewing angle.
+    fn reflectance(cosine: f32, refraction_index: f32) -> f32 {
+        let r0 = ((1.0 - refraction_index) / (1.0 + refraction_index)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+}
+
+impl Material for Dielectric {
+    fn scatter(&self, ray: &Ray, hit: &Hit) -> Option<(Ray, Vec3)> {
+        // `Sphere::hit` always returns an outward-facing normal, so the ray
+        // entered the medium if it points against that normal.
+        let (normal, refraction_ratio) = if ray.direction.dot(hit.normal) < 0.0 {
+            (hit.normal, 1.0 / self.refraction_index)
+        } else {
+            (-hit.normal, self.refraction_index)
+        };
+
+        let unit_direction = ray.direction.unit();
+        let cos_theta = (-unit_direction).dot(normal).min(1.0);
+        let sin_theta = (1.0 - cos_theta*cos_theta).sqrt();
+
+        let cannot_refract = refraction_ratio * sin_theta > 1.0;
+        let direction = if cannot_refract || Self::reflectance(cos_theta, refraction_ratio) > rand::random() {
+            unit_direction.reflect(normal)
+        } else {
+            unit_direction.refract(normal, refraction_ratio)
+        };
+
+        Some((Ray { origin: hit.point, direction, time: ray.time }, Vec3::new(1.0, 1.0, 1.0)))
+    }
+}
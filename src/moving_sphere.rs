@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{Hit, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::sphere::{hit_sphere, sphere_bounding_box};
+use crate::vec3::Vec3;
+
+pub struct MovingSphere {
+    pub radius: f32,
+    pub center0: Vec3,
+    pub center1: Vec3,
+    pub time0: f32,
+    pub time1: f32,
+    pub material: Arc<dyn Material>,
+}
+
+#[test]
+fn hit_point_follows_center_over_time() {
+    use crate::material::Lambertian;
+
+    let material = Arc::new(Lambertian { albedo: Vec3::new(0.5, 0.5, 0.5) }) as Arc<dyn Material>;
+    let sphere = MovingSphere {
+        radius: 1.0,
+        center0: Vec3::new(0.0, 0.0, 0.0),
+        center1: Vec3::new(0.0, 10.0, 0.0),
+        time0: 0.0,
+        time1: 1.0,
+        material,
+    };
+
+    let hit_at_start = sphere.hit(&Ray {
+        origin: Vec3::new(-10.0, 0.0, 0.0),
+        direction: Vec3::new(1.0, 0.0, 0.0),
+        time: 0.0,
+    }, 0.0, f32::INFINITY).map(|h| h.t);
+    assert_eq!(hit_at_start, Some(9.0));
+
+    // At time1 the sphere has moved to center (0, 10, 0), out of this ray's path.
+    let hit_at_end = sphere.hit(&Ray {
+        origin: Vec3::new(-10.0, 0.0, 0.0),
+        direction: Vec3::new(1.0, 0.0, 0.0),
+        time: 1.0,
+    }, 0.0, f32::INFINITY).map(|h| h.t);
+    assert_eq!(hit_at_end, None);
+}
+
+impl MovingSphere {
+    fn center(&self, time: f32) -> Vec3 {
+        let t = (time - self.time0) / (self.time1 - self.time0);
+        self.center0.lerp(self.center1, t)
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, r: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        hit_sphere(self.center(r.time), self.radius, &self.material, r, t_min, t_max)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        Aabb::surrounding_box(
+            &sphere_bounding_box(self.center(self.time0), self.radius),
+            &sphere_bounding_box(self.center(self.time1), self.radius),
+        )
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rand::Rng;
+
+use crate::camera::Camera;
+use crate::hittable::{Hittable, Scene};
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+const SCALE: u8 = 255;
+const TILE_SIZE: u16 = 16;
+
+pub struct RenderSettings {
+    pub width: u16,
+    pub height: u16,
+    pub samples_per_pixel: u32,
+    pub max_depth: u32,
+}
+
+fn sky_color(ray: &Ray) -> Vec3 {
+    let unit_direction = ray.direction.unit();
+    let t = 0.5 * (unit_direction.y + 1.0);
+    Vec3::new(1.0, 1.0, 1.0) * (1.0-t) + Vec3::new(0.5, 0.7, 1.0) * t
+}
+
+fn ray_color(ray: &Ray, scene: &Scene, depth: u32) -> Vec3 {
+    if depth == 0 {
+        return Vec3::new(0.0, 0.0, 0.0);
+    }
+
+    match scene.hit(ray, 0.001, f32::INFINITY) {
+        None => sky_color(ray),
+        Some(hit) => match hit.material.scatter(ray, &hit) {
+            Some((scattered, attenuation)) => attenuation * ray_color(&scattered, scene, depth-1),
+            None => Vec3::new(0.0, 0.0, 0.0),
+        },
+    }
+}
+
+// One sample of a pixel, jittered to a random position within it for anti-aliasing.
+pub fn sample_color(camera: &Camera, scene: &Scene, x: u16, y: u16, settings: &RenderSettings) -> Vec3 {
+    let mut rng = rand::thread_rng();
+    let sample_x = x as f32 + rng.gen::<f32>();
+    let sample_y = y as f32 + rng.gen::<f32>();
+    ray_color(&camera.ray(sample_x, sample_y, settings.width, settings.height), scene, settings.max_depth)
+}
+
+// Averages an accumulated color over its sample count and applies gamma correction.
+pub fn to_pixel(accumulated: Vec3, sample_count: u32) -> [u8; 4] {
+    let scale = 1.0 / sample_count as f32;
+    let r = (accumulated.x * scale).sqrt().clamp(0.0, 1.0);
+    let g = (accumulated.y * scale).sqrt().clamp(0.0, 1.0);
+    let b = (accumulated.z * scale).sqrt().clamp(0.0, 1.0);
+    [(r*SCALE as f32) as u8, (g*SCALE as f32) as u8, (b*SCALE as f32) as u8, 255]
+}
+
+fn render_pixel(camera: &Camera, scene: &Scene, x: u16, y: u16, settings: &RenderSettings) -> [u8; 4] {
+    let mut accumulated = Vec3::new(0.0, 0.0, 0.0);
+    for _ in 0..settings.samples_per_pixel {
+        accumulated = accumulated + sample_color(camera, scene, x, y, settings);
+    }
+    to_pixel(accumulated, settings.samples_per_pixel)
+}
+
+fn tile_origins(width: u16, height: u16) -> Vec<(u16, u16)> {
+    let mut origins = Vec::new();
+    let mut y = 0;
+    while y < height {
+        let mut x = 0;
+        while x < width {
+            origins.push((x, y));
+            x += TILE_SIZE;
+        }
+        y += TILE_SIZE;
+    }
+    origins
+}
+
+pub fn default_thread_count() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// Divides the image into `TILE_SIZE`×`TILE_SIZE` tiles and hands them out to
+// `thread_count` worker threads from a shared work queue, one tile at a time.
+// Camera and scene are read-only and borrowed by every worker; only the
+// pixels a worker computed are written back, and each tile's pixels are
+// disjoint, so no locking is needed for that.
+pub fn render_parallel(camera: &Camera, scene: &Scene, settings: &RenderSettings, thread_count: usize) -> Vec<[u8; 4]> {
+    let tiles = tile_origins(settings.width, settings.height);
+    let next_tile = AtomicUsize::new(0);
+    let mut buffer = vec![[0u8; 4]; settings.width as usize * settings.height as usize];
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..thread_count).map(|_| {
+            scope.spawn(|| {
+                let mut tile_results = Vec::new();
+                loop {
+                    let tile_index = next_tile.fetch_add(1, Ordering::Relaxed);
+                    let Some(&(tile_x, tile_y)) = tiles.get(tile_index) else { break };
+
+                    for y in tile_y..(tile_y + TILE_SIZE).min(settings.height) {
+                        for x in tile_x..(tile_x + TILE_SIZE).min(settings.width) {
+                            let color = render_pixel(camera, scene, x, y, settings);
+                            tile_results.push((x, y, color));
+                        }
+                    }
+                }
+                tile_results
+            })
+        }).collect();
+
+        for worker in workers {
+            for (x, y, color) in worker.join().unwrap() {
+                buffer[y as usize * settings.width as usize + x as usize] = color;
+            }
+        }
+    });
+
+    buffer
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::camera::Camera;
+use crate::hittable::{Hittable, Scene};
+use crate::material::{Dielectric, Lambertian, Material, Metal};
+use crate::moving_sphere::MovingSphere;
+use crate::render::RenderSettings;
+use crate::sphere::Sphere;
+use crate::vec3::Vec3;
+
+pub struct SceneFile {
+    pub camera: Camera,
+    pub scene: Scene,
+    pub settings: RenderSettings,
+}
+
+#[test]
+fn load_builds_camera_scene_and_settings() {
+    let path = std::env::temp_dir().join(format!("scene_file_round_trip_{}.txt", std::process::id()));
+    std::fs::write(&path, "\
+width 40
+height 20
+samples 4
+camera 0 0 0 1 0 0 0 1 0
+material ground diffuse 0.5 0.5 0.5
+sphere 5 0 0 1 ground
+").unwrap();
+
+    let defaults = RenderSettings { width: 255, height: 255, samples_per_pixel: 100, max_depth: 50 };
+    let scene_file = load(&path, defaults).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(scene_file.settings.width, 40);
+    assert_eq!(scene_file.settings.height, 20);
+    assert_eq!(scene_file.settings.samples_per_pixel, 4);
+
+    // The center sample looks straight down +x from the origin at the sphere at x=5.
+    let ray = scene_file.camera.ray(20.0, 10.0, 40, 20);
+    assert_eq!(scene_file.scene.hit(&ray, 0.001, f32::INFINITY).map(|h| h.t), Some(4.0));
+}
+
+#[test]
+fn load_reports_undefined_material() {
+    let path = std::env::temp_dir().join(format!("scene_file_undefined_material_{}.txt", std::process::id()));
+    std::fs::write(&path, "\
+camera 0 0 0 1 0 0 0 1 0
+sphere 5 0 0 1 missing
+").unwrap();
+
+    let defaults = RenderSettings { width: 1, height: 1, samples_per_pixel: 1, max_depth: 1 };
+    let err = match load(&path, defaults) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err,
+    };
+    std::fs::remove_file(&path).unwrap();
+
+    // `{:#}` walks the whole anyhow chain; `{}` alone only shows the outermost context.
+    assert!(format!("{err:#}").contains("undefined material"), "{err:#}");
+}
+
+#[test]
+fn load_reports_wrong_field_count() {
+    let path = std::env::temp_dir().join(format!("scene_file_wrong_field_count_{}.txt", std::process::id()));
+    std::fs::write(&path, "\
+camera 0 0 0 1 0 0 0 1 0
+material ground diffuse 0.5 0.5
+").unwrap();
+
+    let defaults = RenderSettings { width: 1, height: 1, samples_per_pixel: 1, max_depth: 1 };
+    let err = match load(&path, defaults) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err,
+    };
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(format!("{err:#}").contains("expected 3 numbers"), "{err:#}");
+}
+
+#[test]
+fn load_reports_unknown_directive() {
+    let path = std::env::temp_dir().join(format!("scene_file_unknown_directive_{}.txt", std::process::id()));
+    std::fs::write(&path, "\
+camera 0 0 0 1 0 0 0 1 0
+cone 0 0 0 1 1 ground
+").unwrap();
+
+    let defaults = RenderSettings { width: 1, height: 1, samples_per_pixel: 1, max_depth: 1 };
+    let err = match load(&path, defaults) {
+        Ok(_) => panic!("expected an error"),
+        Err(err) => err,
+    };
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(format!("{err:#}").contains("unknown directive 'cone'"), "{err:#}");
+}
+
+// A tiny line-oriented scene format, one directive per line:
+//   camera px py pz dx dy dz hx hy hz
+//   material name diffuse r g b
+//   material name metal r g b fuzz
+//   material name dielectric refraction_index
+//   sphere cx cy cz radius matname
+//   moving_sphere cx0 cy0 cz0 cx1 cy1 cz1 radius matname
+//   width/height/samples/depth value
+// Blank lines and lines starting with `#` are ignored.
+pub fn load(path: &Path, defaults: RenderSettings) -> Result<SceneFile> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("reading scene file {}", path.display()))?;
+
+    let mut materials: HashMap<String, Arc<dyn Material>> = HashMap::new();
+    let mut objects: Vec<Box<dyn Hittable>> = Vec::new();
+    let mut camera = None;
+    let mut settings = defaults;
+
+    for (number, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        parse_line(line, &mut materials, &mut objects, &mut camera, &mut settings)
+            .with_context(|| format!("{}:{}: {raw_line}", path.display(), number + 1))?;
+    }
+
+    let camera = camera.with_context(|| format!("scene file {} has no camera directive", path.display()))?;
+    if objects.is_empty() {
+        bail!("scene file {} defines no spheres", path.display());
+    }
+
+    Ok(SceneFile { camera, scene: Scene::new(objects), settings })
+}
+
+fn parse_line(
+    line: &str,
+    materials: &mut HashMap<String, Arc<dyn Material>>,
+    objects: &mut Vec<Box<dyn Hittable>>,
+    camera: &mut Option<Camera>,
+    settings: &mut RenderSettings,
+) -> Result<()> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    match fields[0] {
+        "camera" => {
+            let v = parse_floats(&fields[1..], 9)?;
+            let direction = Vec3::new(v[3], v[4], v[5]);
+            *camera = Some(Camera::new(
+                Vec3::new(v[0], v[1], v[2]),
+                direction,
+                Vec3::new(v[6], v[7], v[8]),
+                0.0,
+                direction.length(),
+                0.0,
+                1.0,
+            ));
+        }
+        "material" => {
+            let name = field(&fields, 1)?.to_string();
+            let material: Arc<dyn Material> = match field(&fields, 2)? {
+                "diffuse" => {
+                    let v = parse_floats(&fields[3..], 3)?;
+                    Arc::new(Lambertian { albedo: Vec3::new(v[0], v[1], v[2]) })
+                }
+                "metal" => {
+                    let v = parse_floats(&fields[3..], 4)?;
+                    Arc::new(Metal { albedo: Vec3::new(v[0], v[1], v[2]), fuzz: v[3] })
+                }
+                "dielectric" => {
+                    let v = parse_floats(&fields[3..], 1)?;
+                    Arc::new(Dielectric { refraction_index: v[0] })
+                }
+                other => bail!("unknown material kind '{other}'"),
+            };
+            materials.insert(name, material);
+        }
+        "sphere" => {
+            let v = parse_floats(&fields[1..], 4)?;
+            let matname = field(&fields, 5)?;
+            let material = materials.get(matname)
+                .ok_or_else(|| anyhow!("undefined material '{matname}'"))?
+                .clone();
+            objects.push(Box::new(Sphere {
+                position: Vec3::new(v[0], v[1], v[2]),
+                radius: v[3],
+                material,
+            }));
+        }
+        "moving_sphere" => {
+            let v = parse_floats(&fields[1..], 7)?;
+            let matname = field(&fields, 8)?;
+            let material = materials.get(matname)
+                .ok_or_else(|| anyhow!("undefined material '{matname}'"))?
+                .clone();
+            objects.push(Box::new(MovingSphere {
+                center0: Vec3::new(v[0], v[1], v[2]),
+                center1: Vec3::new(v[3], v[4], v[5]),
+                radius: v[6],
+                // Matches the 0..1 shutter window the `camera` directive opens.
+                time0: 0.0,
+                time1: 1.0,
+                material,
+            }));
+        }
+        "width" => settings.width = field(&fields, 1)?.parse().context("width")?,
+        "height" => settings.height = field(&fields, 1)?.parse().context("height")?,
+        "samples" => settings.samples_per_pixel = field(&fields, 1)?.parse().context("samples")?,
+        "depth" => settings.max_depth = field(&fields, 1)?.parse().context("depth")?,
+        other => bail!("unknown directive '{other}'"),
+    }
+
+    Ok(())
+}
+
+fn field<'a>(fields: &[&'a str], index: usize) -> Result<&'a str> {
+    fields.get(index).copied().ok_or_else(|| anyhow!("missing field {index}"))
+}
+
+fn parse_floats(fields: &[&str], count: usize) -> Result<Vec<f32>> {
+    if fields.len() < count {
+        bail!("expected {count} numbers, got {}", fields.len());
+    }
+    fields[..count].iter().map(|f| f.parse::<f32>().map_err(anyhow::Error::from)).collect()
+}
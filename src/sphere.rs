@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{Hit, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+
+pub struct Sphere {
+    pub radius: f32,
+    pub position: Vec3,
+    pub material: Arc<dyn Material>,
+}
+
+#[test]
+fn test_sphere_intersections() {
+    use crate::material::Lambertian;
+
+    let material: Arc<dyn Material> = Arc::new(Lambertian { albedo: Vec3::new(0.5, 0.5, 0.5) });
+    let s = Sphere { radius: 1.0, position: Vec3::new(0.0, 0.0, 0.0), material };
+
+    // Ray intersects
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(-10.0, 0.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, f32::INFINITY).map(|h| h.t),
+        Some(9.0));
+
+    // Ray points wrong way
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(-10.0, 0.0, 0.0),
+            direction: Vec3::new(-1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, f32::INFINITY).map(|h| h.t),
+        None);
+
+    // Ray starts inside
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(0.0, 0.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, f32::INFINITY).map(|h| h.t),
+        Some(1.0));
+
+    // Ray hits edge
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(-10.0, 1.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, f32::INFINITY).map(|h| h.t),
+        Some(10.0));
+
+    // Ray misses
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(-10.0, 2.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, f32::INFINITY).map(|h| h.t),
+        None);
+
+    // t_max cuts off hits beyond the window
+    assert_eq!(
+        s.hit(&Ray {
+            origin: Vec3::new(-10.0, 0.0, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        }, 0.0, 5.0).map(|h| h.t),
+        None);
+
+    let hit = s.hit(&Ray {
+        origin: Vec3::new(-10.0, 0.0, 0.0),
+        direction: Vec3::new(1.0, 0.0, 0.0),
+        time: 0.0,
+    }, 0.0, f32::INFINITY).unwrap();
+    assert_eq!(hit.point, Vec3::new(-1.0, 0.0, 0.0));
+    assert_eq!(hit.normal, Vec3::new(-1.0, 0.0, 0.0));
+}
+
+// Shared by `Sphere` and `MovingSphere`, which only differ in how they pick
+// `center` for a given ray.
+pub(crate) fn hit_sphere(center: Vec3, radius: f32, material: &Arc<dyn Material>, r: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+    // If a point P is on the sphere S with radius r, centered at origin,
+    // it must satisfy:
+    //   ‖P‖ = r
+    // that is:
+    //   P_x² + P_y² + P_z² = r²
+    //
+    // If the sphere is not at origin:
+    //   ‖P-S‖ = r
+    //   (P_x-S_x)² + (P_y-S_y)² + (P_z-S_z)² = r²
+    //   (P-S) ⋅ (P-S) = r²
+    //
+    // A Point P(t) is on the Ray R with origin O and direction D, if for some t≥0:
+    //  O + tD = P(t), so  O_c + tD_c = P_c, for every coordinate c∈{x, y, z}
+    //
+    // Therefore a ray on the sphere needs to satisfy
+    //   (O + tD − S)⋅(O + tD − S) = r²
+    //   (D ⋅ D)t² + 2(D ⋅ (O − S))t + (O − S)⋅(O − S) − r² = 0
+    //
+    // Quadratic polynomials (ax²+bx+c=0) are solved by:
+    //   x = (-b±√(b²-4ac)) / 2a
+    // where the part inside the square root is called the discriminant.
+
+    // (O − S)
+    let os = r.origin - center;
+
+    // a = D ⋅ D, which is always positive
+    let a = r.direction.dot(r.direction);
+
+    // b = 2(D ⋅ (O − S))
+    let b = 2.0 * r.direction.dot(os);
+
+    //   c = (O − S)⋅(O − S) − r²
+    let c = os.dot(os) - radius * radius;
+    let discriminant = b*b - 4.0*a*c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    // Try the closer root first, then the farther one, keeping whichever
+    // lands strictly inside the (t_min, t_max) window.
+    let sqrtd = discriminant.sqrt();
+    let mut t = (-b - sqrtd) / (2.0*a);
+    if t <= t_min || t >= t_max {
+        t = (-b + sqrtd) / (2.0*a);
+        if t <= t_min || t >= t_max {
+            return None;
+        }
+    }
+
+    let point = r.at(t);
+    let normal = (point - center) / radius;
+    Some(Hit { t, point, normal, material: material.clone() })
+}
+
+// Shared by `Sphere` and `MovingSphere`: the box enclosing a sphere of
+// `radius` centered at `center`.
+pub(crate) fn sphere_bounding_box(center: Vec3, radius: f32) -> Aabb {
+    let r = Vec3::new(radius, radius, radius);
+    Aabb::new(center - r, center + r)
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, r: &Ray, t_min: f32, t_max: f32) -> Option<Hit> {
+        hit_sphere(self.position, self.radius, &self.material, r, t_min, t_max)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        sphere_bounding_box(self.position, self.radius)
+    }
+}
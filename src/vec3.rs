@@ -1,6 +1,8 @@
 use std::convert::Into;
 use std::ops;
 
+use rand::Rng;
+
 #[derive(Clone, Copy, Debug)]
 pub struct Vec3 {
     pub x: f32,
@@ -106,6 +108,15 @@ T: Copy,
     }
 }
 
+// Component-wise (Hadamard) product, used to multiply an attenuation color
+// into an incoming ray color.
+impl ops::Mul<Vec3> for Vec3 {
+    type Output = Self;
+    fn mul(self, other: Vec3) -> Vec3 {
+        Vec3 { x: self.x*other.x, y: self.y*other.y, z: self.z*other.z }
+    }
+}
+
 impl Vec3 {
     pub fn new<T>(x: T, y: T, z: T) -> Vec3 where T: Into<f32> {
         Vec3 { x: x.into(), y: y.into(), z: z.into() }
@@ -126,5 +137,66 @@ impl Vec3 {
     pub fn dot(self, other: Vec3) -> f32 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
+
+    pub fn unit(self) -> Vec3 {
+        self / self.length()
+    }
+
+    pub fn lerp(self, other: Vec3, t: f32) -> Vec3 {
+        self * (1.0-t) + other * t
+    }
+
+    // True if every component is close enough to zero that treating this
+    // as a direction would be numerically useless (e.g. a scatter direction
+    // that exactly cancels the surface normal).
+    pub fn near_zero(self) -> bool {
+        const EPS: f32 = 1e-8;
+        self.x.abs() < EPS && self.y.abs() < EPS && self.z.abs() < EPS
+    }
+
+    pub fn reflect(self, normal: Vec3) -> Vec3 {
+        self - normal * 2.0 * self.dot(normal)
+    }
+
+    // `self` must be a unit vector pointing into the surface;
+    // `etai_over_etat` is the ratio of refraction indices (incident / transmitted).
+    pub fn refract(self, normal: Vec3, etai_over_etat: f32) -> Vec3 {
+        let cos_theta = (-self).dot(normal).min(1.0);
+        let r_out_perp = (self + normal * cos_theta) * etai_over_etat;
+        let r_out_parallel = normal * -(1.0 - r_out_perp.dot(r_out_perp)).abs().sqrt();
+        r_out_perp + r_out_parallel
+    }
+
+    pub fn random_range(min: f32, max: f32) -> Vec3 {
+        let mut rng = rand::thread_rng();
+        Vec3 {
+            x: rng.gen_range(min..max),
+            y: rng.gen_range(min..max),
+            z: rng.gen_range(min..max),
+        }
+    }
+
+    pub fn random_in_unit_sphere() -> Vec3 {
+        loop {
+            let candidate = Vec3::random_range(-1.0, 1.0);
+            if candidate.dot(candidate) < 1.0 {
+                return candidate;
+            }
+        }
+    }
+
+    pub fn random_unit_vector() -> Vec3 {
+        Vec3::random_in_unit_sphere().unit()
+    }
+
+    pub fn random_in_unit_disc() -> Vec3 {
+        let mut rng = rand::thread_rng();
+        loop {
+            let candidate = Vec3 { x: rng.gen_range(-1.0..1.0), y: rng.gen_range(-1.0..1.0), z: 0.0 };
+            if candidate.dot(candidate) < 1.0 {
+                return candidate;
+            }
+        }
+    }
 }
 